@@ -0,0 +1,30 @@
+//! # wavegen
+//!
+//! Easy sample-based waveform generator.
+//!
+//! A [`Waveform`] is built up from one or more [`PeriodicFunction`]s - components such as
+//! [`sine`], [`sawtooth`] or [`square`] - which are summed together and evaluated one sample
+//! at a time (via the [`Iterator`] implementation) or in bulk (via
+//! [`Waveform::sample_into`]).
+//!
+//! ## Custom periodic functions
+//!
+//! Any `Fn(f64) -> f64 + Send + Sync` closure can be used as a component - see
+//! [`periodic_functions::custom`].
+
+#![cfg_attr(not(feature = "std"), no_std)]
+
+extern crate alloc;
+
+pub mod periodic_functions;
+pub mod waveform;
+
+pub use periodic_functions::{
+    chirp, dc_bias, pink_noise, sawtooth, sawtooth_bl, sine, sine_lut, square, square_bl,
+    white_noise,
+};
+pub use waveform::{Sample, Waveform};
+
+/// The building block of a [`Waveform`] - a boxed closure mapping a point in time (in
+/// seconds) to a sample value.
+pub type PeriodicFunction = alloc::boxed::Box<dyn Fn(f64) -> f64 + Send + Sync>;
@@ -2,7 +2,8 @@
 
 use crate::PeriodicFunction;
 use alloc::boxed::Box;
-use core::f64::consts::PI;
+use alloc::vec::Vec;
+use core::f64::consts::{PI, TAU};
 
 /// Helper wrapping a custom periodic function
 /// See: [Custom periodic functions]
@@ -61,11 +62,89 @@ fn frac(x: f64) -> f64 {
     frac
 }
 
+/// Vectorized phase-wrapping helpers for bulk sample evaluation, used by the batch
+/// evaluation fast path (e.g. `Waveform::sample_into`) for `sawtooth`/`square`-shaped
+/// signals, whose per-sample cost is otherwise dominated by the scalar `frac`/`fract` call.
+/// Gated behind the `simd` feature so the default `no_std`/`libm` build is unaffected.
+#[cfg(feature = "simd")]
+pub(crate) mod simd {
+    /// Writes `x - trunc(x)` (the fractional part, rounding toward zero) of every element
+    /// of `input` into `out`. Panics if the slices differ in length.
+    pub(crate) fn frac_into(input: &[f64], out: &mut [f64]) {
+        assert_eq!(input.len(), out.len());
+
+        #[cfg(all(target_arch = "x86_64", target_feature = "avx"))]
+        {
+            frac_into_avx(input, out);
+        }
+
+        #[cfg(not(all(target_arch = "x86_64", target_feature = "avx")))]
+        {
+            for (x, y) in input.iter().zip(out.iter_mut()) {
+                *y = x.fract();
+            }
+        }
+    }
+
+    #[cfg(all(target_arch = "x86_64", target_feature = "avx"))]
+    fn frac_into_avx(input: &[f64], out: &mut [f64]) {
+        use core::arch::x86_64::{
+            _mm256_loadu_pd, _mm256_round_pd, _mm256_storeu_pd, _mm256_sub_pd,
+            _MM_FROUND_NO_EXC, _MM_FROUND_TO_ZERO,
+        };
+
+        let chunks = input.len() / 4;
+
+        // SAFETY: each iteration only loads/stores the 4 lanes at `base..base + 4`, and
+        // `chunks * 4 <= input.len() == out.len()`, so every access stays in bounds.
+        unsafe {
+            for i in 0..chunks {
+                let base = i * 4;
+                let x = _mm256_loadu_pd(input.as_ptr().add(base));
+                let truncated = _mm256_round_pd(x, _MM_FROUND_TO_ZERO | _MM_FROUND_NO_EXC);
+                let frac = _mm256_sub_pd(x, truncated);
+                _mm256_storeu_pd(out.as_mut_ptr().add(base), frac);
+            }
+        }
+
+        for i in (chunks * 4)..input.len() {
+            out[i] = input[i].fract();
+        }
+    }
+}
+
+/// Fills `out` with the fractional part of every element of `input`, used by
+/// [`crate::waveform::Waveform::sample_into`]'s batch path. Dispatches to the vectorized
+/// [`simd::frac_into`] when the `simd` feature is enabled, falling back to the scalar [`frac`]
+/// otherwise. Panics if the slices differ in length.
+pub(crate) fn bulk_frac(input: &[f64], out: &mut [f64]) {
+    #[cfg(feature = "simd")]
+    {
+        simd::frac_into(input, out);
+    }
+
+    #[cfg(not(feature = "simd"))]
+    {
+        assert_eq!(input.len(), out.len());
+
+        for (x, y) in input.iter().zip(out.iter_mut()) {
+            *y = frac(*x);
+        }
+    }
+}
+
+/// Scalar sawtooth evaluation, shared by [`sawtooth`] and the per-sample [`Iterator`] path of
+/// [`crate::waveform::Waveform`]. [`crate::waveform::Waveform::sample_into`]'s batch path
+/// applies the same formula to the output of [`bulk_frac`].
+pub(crate) fn sawtooth_sample(t: f64, frequency: f64, amplitude: f64, phase: f64) -> f64 {
+    2.0 * amplitude * frac(t * frequency + phase) - amplitude
+}
+
 /// Sawtooth function builder. See the [`macro`] for more info.
 ///
 /// [`macro`]: ../macro.sawtooth.html
 pub fn sawtooth(frequency: f64, amplitude: f64, phase: f64) -> PeriodicFunction {
-    Box::new(move |t| 2.0 * amplitude * frac(t * frequency + phase) - amplitude)
+    Box::new(move |t| sawtooth_sample(t, frequency, amplitude, phase))
 }
 
 /// Builder macro for Sine [PeriodicFunction].
@@ -178,38 +257,285 @@ macro_rules! sine {
 }
 
 #[cfg(all(not(feature = "libm"), feature = "std"))]
-fn _square(frequency: f64, amplitude: f64, phase: f64) -> PeriodicFunction {
-    // TODO: implement duty cycle control
+fn sin_raw(x: f64) -> f64 {
+    x.sin()
+}
+
+#[cfg(feature = "libm")]
+fn sin_raw(x: f64) -> f64 {
+    libm::sin(x)
+}
+
+#[cfg(all(not(feature = "libm"), feature = "std"))]
+fn wrap01(x: f64) -> f64 {
+    x - x.floor()
+}
+
+#[cfg(feature = "libm")]
+fn wrap01(x: f64) -> f64 {
+    use libm::floor;
+    x - floor(x)
+}
+
+/// Default size of the wavetable built by [`sine_lut`]. 512 entries, linearly interpolated,
+/// keep the approximation error well under the `EPS` used in this crate's own tests while
+/// staying small enough for memory constrained `no_std` targets.
+pub const DEFAULT_LUT_SIZE: usize = 512;
+
+/// Builds a cosine wavetable of `size + 1` entries spanning one full turn (the extra entry
+/// duplicates index `0`, so interpolation never needs to wrap the index).
+fn cosine_table(size: usize) -> Vec<f64> {
+    (0..=size)
+        .map(|i| sin_raw((i as f64) * TAU / (size as f64) + PI / 2.0))
+        .collect()
+}
+
+/// Table-based, linearly-interpolated Sine function builder. See the [`macro`] for more info.
+///
+/// Unlike [`sine`], which calls `sin`/`libm::sin` on every sample, this precomputes a cosine
+/// wavetable once up front and interpolates into it per sample, trading a little accuracy
+/// (and the table's one-off setup cost) for much cheaper per-sample evaluation. This matters
+/// most on targets without a hardware FPU, where `sin` is comparatively expensive.
+///
+/// `size` should be a power of two; a larger table trades memory for accuracy.
+///
+/// [`macro`]: ../macro.sine_lut.html
+pub fn sine_lut(frequency: f64, amplitude: f64, phase: f64, size: usize) -> PeriodicFunction {
+    let table = cosine_table(size);
+    let n = size as f64;
+
     Box::new(move |t| {
-        let power = (2.0 * (t - phase) * frequency).floor() as i32;
+        // table[i] holds cos(i * TAU / size); offsetting the phase by a quarter turn
+        // turns the cosine table into a sine: cos(2*pi*(x - 0.25)) == sin(2*pi*x).
+        let p = wrap01(t * frequency + phase - 0.25);
+        let f = p * n;
+        // `wrap01` is meant to stay in [0, 1), but can round up to exactly `1.0` right at the
+        // top of its range, which would otherwise push `i` one past the table's last pair
+        let i = (f as usize).min(size - 1);
+        let frac_part = f - (i as f64);
+
+        let a = table[i];
+        let b = table[i + 1];
 
-        amplitude * (-1f64).powi(power)
+        amplitude * (a + frac_part * (b - a))
     })
 }
 
+/// Builder macro for table-based Sine [PeriodicFunction].
+///
+/// Takes up to 4 arguments - frequency {amplitude, {phase, {size}}}. Behaves like [`sine!`],
+/// except evaluation is backed by a linearly-interpolated wavetable instead of calling
+/// `sin`/`libm::sin` per sample - see [`sine_lut`] for the tradeoff.
+///
+/// | argument | unit | notes |
+/// | -------- | ---- | ----- |
+/// | frequency | Hz | Frequecy of the periodic function. Also: 1 / period |
+/// | amplitude | *arbitrary* | The amplitude of the function in 0-peak notation. |
+/// | phase | *periods* | The phase shift of the function. Value of 1 means full shift around.
+/// | size | *entries* | Wavetable size. Defaults to [`DEFAULT_LUT_SIZE`]. Should be a power of two. |
+///
+/// # Examples
+///
+/// 50 Hz table-based sine of amplitude 1 and no phase shift
+/// ```
+/// use wavegen::sine_lut;
+///
+/// let sine = sine_lut!(50);
+/// ```
+///
+/// Same, but with a smaller, less accurate 64-entry table
+/// ```
+/// use wavegen::sine_lut;
+///
+/// let sine = sine_lut!(50, 1.0, 0.0, 64);
+/// ```
+#[macro_export]
+macro_rules! sine_lut {
+    (frequency: $frequency:expr) => {
+        sine_lut!($frequency)
+    };
+    (frequency: $frequency:expr, amplitude: $amplitude:expr) => {
+        sine_lut!($frequency, $amplitude)
+    };
+    (frequency: $frequency:expr, amplitude: $amplitude:expr, phase: $phase:expr) => {
+        sine_lut!($frequency, $amplitude, $phase)
+    };
+    (frequency: $frequency:expr, amplitude: $amplitude:expr, phase: $phase:expr, size: $size:expr) => {
+        sine_lut!($frequency, $amplitude, $phase, $size)
+    };
+    ($frequency:expr) => {
+        sine_lut!($frequency, 1.0, 0.0)
+    };
+    ($frequency:expr, $amplitude:expr) => {
+        sine_lut!($frequency, $amplitude, 0.0)
+    };
+    ($frequency:expr, $amplitude:expr, $phase:expr) => {
+        sine_lut!(
+            $frequency,
+            $amplitude,
+            $phase,
+            $crate::periodic_functions::DEFAULT_LUT_SIZE
+        )
+    };
+    ($frequency:expr, $amplitude:expr, $phase:expr, $size:expr) => {
+        $crate::periodic_functions::sine_lut(
+            $frequency as f64,
+            $amplitude as f64,
+            $phase as f64,
+            $size as usize,
+        )
+    };
+}
+
+#[cfg(all(not(feature = "libm"), feature = "std"))]
+fn powf_raw(base: f64, exponent: f64) -> f64 {
+    base.powf(exponent)
+}
+
+#[cfg(feature = "libm")]
+fn powf_raw(base: f64, exponent: f64) -> f64 {
+    libm::pow(base, exponent)
+}
+
+#[cfg(all(not(feature = "libm"), feature = "std"))]
+fn ln_raw(x: f64) -> f64 {
+    x.ln()
+}
+
 #[cfg(feature = "libm")]
-fn _square(frequency: f64, amplitude: f64, phase: f64) -> PeriodicFunction {
-    // TODO: implement duty cycle control
-    use libm::{floor, pow};
-    Box::new(move |t| amplitude * pow(-1.0, floor(2.0 * (t - phase) * frequency)))
+fn ln_raw(x: f64) -> f64 {
+    libm::log(x)
+}
+
+/// Selects the frequency-sweep profile used by [`chirp`].
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum SweepKind {
+    /// Instantaneous frequency moves from `f_start` to `f_end` linearly over the sweep duration.
+    Linear,
+    /// Instantaneous frequency moves from `f_start` to `f_end` exponentially over the sweep
+    /// duration. Sweeps more slowly at low frequencies and faster at high ones, which is often
+    /// preferred when the sweep spans multiple decades.
+    Exponential,
+}
+
+/// Chirp (frequency sweep) function builder. See the [`macro`] for more info.
+///
+/// [`macro`]: ../macro.chirp.html
+pub fn chirp(
+    f_start: f64,
+    f_end: f64,
+    duration: f64,
+    amplitude: f64,
+    kind: SweepKind,
+) -> PeriodicFunction {
+    match kind {
+        SweepKind::Linear => {
+            let k = (f_end - f_start) / duration;
+
+            Box::new(move |t| sin_raw(TAU * (f_start * t + 0.5 * k * t * t)) * amplitude)
+        }
+        SweepKind::Exponential => {
+            // the closed-form exponential sweep divides by `ln(f_end / f_start)`, which is
+            // zero when the endpoints coincide and undefined when `f_start` is zero, so both
+            // degenerate cases fall back to a plain constant-frequency sine instead of NaN-ing
+            if f_start == 0.0 || f_start == f_end {
+                Box::new(move |t| sin_raw(TAU * f_start * t) * amplitude)
+            } else {
+                let r = powf_raw(f_end / f_start, 1.0 / duration);
+                let ln_r = ln_raw(r);
+
+                Box::new(move |t| {
+                    sin_raw(TAU * f_start * ((powf_raw(r, t) - 1.0) / ln_r)) * amplitude
+                })
+            }
+        }
+    }
+}
+
+/// Builder macro for Chirp (frequency sweep) [PeriodicFunction].
+///
+/// Takes 4 or 5 arguments - f_start, f_end, duration, {amplitude,} kind.
+///
+/// | argument | unit | notes |
+/// | -------- | ---- | ----- |
+/// | f_start | Hz | Instantaneous frequency at the start of the sweep (`t = 0`). |
+/// | f_end | Hz | Instantaneous frequency at the end of the sweep (`t = duration`). |
+/// | duration | seconds | Time over which the sweep from `f_start` to `f_end` takes place. |
+/// | amplitude | *arbitrary* | The amplitude of the function in 0-peak notation. |
+/// | kind | [`SweepKind`] | Whether the frequency sweeps linearly or exponentially. |
+///
+/// # Examples
+///
+/// Linear sweep from 10 Hz to 1000 Hz over 5 seconds
+/// ```
+/// use wavegen::chirp;
+/// use wavegen::periodic_functions::SweepKind;
+///
+/// let sweep = chirp!(10, 1000, 5, SweepKind::Linear);
+/// ```
+#[macro_export]
+macro_rules! chirp {
+    ($f_start:expr, $f_end:expr, $duration:expr, $kind:expr) => {
+        chirp!($f_start, $f_end, $duration, 1.0, $kind)
+    };
+    ($f_start:expr, $f_end:expr, $duration:expr, $amplitude:expr, $kind:expr) => {
+        $crate::periodic_functions::chirp(
+            $f_start as f64,
+            $f_end as f64,
+            $duration as f64,
+            $amplitude as f64,
+            $kind,
+        )
+    };
+}
+
+/// Scalar square evaluation, shared by [`square`] and the per-sample [`Iterator`] path of
+/// [`crate::waveform::Waveform`]. [`crate::waveform::Waveform::sample_into`]'s batch path
+/// applies the same comparison to the output of [`bulk_frac`].
+pub(crate) fn square_sample(t: f64, frequency: f64, amplitude: f64, phase: f64, duty: f64) -> f64 {
+    let mut u = frac((t - phase) * frequency);
+    // `frac` keeps the sign of its argument; move negative fractions into the period's [0, 1)
+    if u < 0.0 {
+        u += 1.0;
+    }
+
+    if u < duty {
+        amplitude
+    } else {
+        -amplitude
+    }
+}
+
+fn _square(frequency: f64, amplitude: f64, phase: f64, duty: f64) -> PeriodicFunction {
+    Box::new(move |t| square_sample(t, frequency, amplitude, phase, duty))
 }
 
 /// Square function builder. See the [`macro`] for more info.
 ///
 /// [`macro`]: ../macro.square.html
-pub fn square(frequency: f64, amplitude: f64, phase: f64) -> PeriodicFunction {
-    _square(frequency, amplitude, phase)
+pub fn square(frequency: f64, amplitude: f64, phase: f64, duty: f64) -> PeriodicFunction {
+    _square(frequency, amplitude, phase, duty)
 }
 
 /// Builder macro for Square [PeriodicFunction].
 ///
-/// Takes up to 3 arguments - frequency {amplitude, {phase}}
+/// Takes up to 4 arguments - frequency {amplitude, {phase, {duty}}}
 ///
 /// | argument | unit | notes |
 /// | -------- | ---- | ----- |
 /// | frequency | Hz | Frequecy of the periodic function. Also: 1 / period |
 /// | amplitude | *arbitrary* | The amplitude of the function in 0-peak notation. |
 /// | phase | *periods* | The phase shift of the function. Value of 1 means full shift around.
+/// | duty | *ratio* | Fraction of each period spent at `+amplitude`. Defaults to `0.5`. |
+///
+/// # Examples
+///
+/// 50 Hz square of amplitude 1, no phase shift and a duty cycle of 25%
+/// ```
+/// use wavegen::square;
+///
+/// let square = square!(50, 1.0, 0.0, 0.25);
+/// ```
 #[macro_export]
 macro_rules! square {
     (frequency: $frequency:expr) => {
@@ -219,7 +545,10 @@ macro_rules! square {
         square!($frequency, $amplitude)
     };
     (frequency: $frequency:expr, amplitude: $amplitude:expr, phase: $phase:expr) => {
-        square!($frequency, $amplitude, 0.0)
+        square!($frequency, $amplitude, $phase)
+    };
+    (frequency: $frequency:expr, amplitude: $amplitude:expr, phase: $phase:expr, duty: $duty:expr) => {
+        square!($frequency, $amplitude, $phase, $duty)
     };
     ($frequency:expr) => {
         square!($frequency, 1.0, 0.0)
@@ -228,7 +557,306 @@ macro_rules! square {
         square!($frequency, $amplitude, 0.0)
     };
     ($frequency:expr, $amplitude:expr, $phase:expr) => {
-        $crate::periodic_functions::square($frequency as f64, $amplitude as f64, $phase as f64)
+        square!($frequency, $amplitude, $phase, 0.5)
+    };
+    ($frequency:expr, $amplitude:expr, $phase:expr, $duty:expr) => {
+        $crate::periodic_functions::square(
+            $frequency as f64,
+            $amplitude as f64,
+            $phase as f64,
+            $duty as f64,
+        )
+    };
+}
+
+/// splitmix64 - a fast, well-distributed 64 bit hash, used to turn a sample index into a
+/// uniform pseudo-random value. See <https://prng.di.unimi.it/splitmix64.c>.
+fn splitmix64(seed: u64) -> u64 {
+    let mut z = seed.wrapping_add(0x9E37_79B9_7F4A_7C15);
+    z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+    z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+    z ^ (z >> 31)
+}
+
+#[cfg(all(not(feature = "libm"), feature = "std"))]
+fn round_raw(x: f64) -> f64 {
+    x.round()
+}
+
+#[cfg(feature = "libm")]
+fn round_raw(x: f64) -> f64 {
+    libm::round(x)
+}
+
+/// Hashes `(seed, index)` into a value uniformly distributed over `[-1, 1)`.
+fn uniform_bipolar(seed: u64, index: u64) -> f64 {
+    let bits = splitmix64(seed ^ index.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    // top 53 bits -> a uniform value in [0, 1), then rescale to [-1, 1)
+    let unit = ((bits >> 11) as f64) * (1.0 / (1u64 << 53) as f64);
+
+    unit * 2.0 - 1.0
+}
+
+/// White noise function builder. See the [`macro`] for more info.
+///
+/// Since a [`PeriodicFunction`] only ever receives the time `t`, the underlying sample index
+/// is reconstructed as `(t * sample_rate).round()`. That index, combined with `seed`, is
+/// hashed through a splitmix64 step into a value uniform over `[-amplitude, amplitude)`.
+/// Evaluation is therefore a pure function of `t` - reproducible and independent of the order
+/// or range in which samples are requested, unlike a stateful RNG.
+///
+/// [`macro`]: ../macro.white_noise.html
+pub fn white_noise(amplitude: f64, seed: u64, sample_rate: f64) -> PeriodicFunction {
+    Box::new(move |t| {
+        let index = round_raw(t * sample_rate) as u64;
+
+        amplitude * uniform_bipolar(seed, index)
+    })
+}
+
+/// Builder macro for White noise [PeriodicFunction].
+///
+/// Takes up to 3 arguments - sample_rate, {seed, {amplitude}}.
+///
+/// | argument | unit | notes |
+/// | -------- | ---- | ----- |
+/// | sample_rate | Hz | Sample rate of the [`Waveform`] this noise will be mixed into. |
+/// | seed | *arbitrary* | Seed for the deterministic hash. Same seed -> same noise. |
+/// | amplitude | *arbitrary* | The amplitude of the function in 0-peak notation. |
+///
+/// [`Waveform`]: ../struct.Waveform.html
+///
+/// # Examples
+///
+/// White noise matching a 44100 Hz [`Waveform`], seeded with `0`
+/// ```
+/// use wavegen::white_noise;
+///
+/// let noise = white_noise!(44100);
+/// ```
+#[macro_export]
+macro_rules! white_noise {
+    ($sample_rate:expr) => {
+        white_noise!($sample_rate, 0)
+    };
+    ($sample_rate:expr, $seed:expr) => {
+        white_noise!($sample_rate, $seed, 1.0)
+    };
+    ($sample_rate:expr, $seed:expr, $amplitude:expr) => {
+        $crate::periodic_functions::white_noise(
+            $amplitude as f64,
+            $seed as u64,
+            $sample_rate as f64,
+        )
+    };
+}
+
+/// Number of octave accumulators summed by [`pink_noise`]. Each accumulator halves in update
+/// rate relative to the previous one, covering 16 octaves of a Voss-McCartney pink spectrum.
+const PINK_NOISE_OCTAVES: u32 = 16;
+
+/// Pink (-3 dB/octave) noise function builder. See the [`macro`] for more info.
+///
+/// Implements the Voss-McCartney method: [`PINK_NOISE_OCTAVES`] octave accumulators are
+/// summed, where the `k`-th accumulator only changes value every `2^k` samples. Since a
+/// [`PeriodicFunction`] must stay a pure `Fn(f64) -> f64`, rather than updating accumulators
+/// in place, each one is recomputed from `index >> k`, which is constant over exactly the
+/// `2^k` samples it would otherwise have been held for. Like [`white_noise`], evaluation is
+/// index-based, so it is reproducible and position-independent.
+///
+/// [`macro`]: ../macro.pink_noise.html
+pub fn pink_noise(amplitude: f64, seed: u64, sample_rate: f64) -> PeriodicFunction {
+    Box::new(move |t| {
+        let index = round_raw(t * sample_rate) as u64;
+
+        let sum: f64 = (0..PINK_NOISE_OCTAVES)
+            .map(|octave| uniform_bipolar(seed ^ (u64::from(octave) + 1), index >> octave))
+            .sum();
+
+        amplitude * (sum / f64::from(PINK_NOISE_OCTAVES))
+    })
+}
+
+/// Builder macro for Pink noise [PeriodicFunction].
+///
+/// Takes up to 3 arguments - sample_rate, {seed, {amplitude}}.
+///
+/// | argument | unit | notes |
+/// | -------- | ---- | ----- |
+/// | sample_rate | Hz | Sample rate of the [`Waveform`] this noise will be mixed into. |
+/// | seed | *arbitrary* | Seed for the deterministic hash. Same seed -> same noise. |
+/// | amplitude | *arbitrary* | The amplitude of the function in 0-peak notation. |
+///
+/// [`Waveform`]: ../struct.Waveform.html
+///
+/// # Examples
+///
+/// Pink noise matching a 44100 Hz [`Waveform`], seeded with `0`
+/// ```
+/// use wavegen::pink_noise;
+///
+/// let noise = pink_noise!(44100);
+/// ```
+#[macro_export]
+macro_rules! pink_noise {
+    ($sample_rate:expr) => {
+        pink_noise!($sample_rate, 0)
+    };
+    ($sample_rate:expr, $seed:expr) => {
+        pink_noise!($sample_rate, $seed, 1.0)
+    };
+    ($sample_rate:expr, $seed:expr, $amplitude:expr) => {
+        $crate::periodic_functions::pink_noise(
+            $amplitude as f64,
+            $seed as u64,
+            $sample_rate as f64,
+        )
+    };
+}
+
+#[cfg(all(not(feature = "libm"), feature = "std"))]
+fn floor_raw(x: f64) -> f64 {
+    x.floor()
+}
+
+#[cfg(feature = "libm")]
+fn floor_raw(x: f64) -> f64 {
+    libm::floor(x)
+}
+
+/// Band-limited Sawtooth function builder. See the [`macro`] for more info.
+///
+/// The naive [`sawtooth`] has a single discontinuity per period, which carries energy at
+/// every harmonic and aliases heavily once sampled. This instead synthesizes the sawtooth
+/// as a Fourier series truncated to the harmonics below the Nyquist limit of `sample_rate`,
+/// trading a (much) higher per-sample cost for a clean, alias-free spectrum - prefer this
+/// over [`sawtooth`] whenever the output feeds into spectral analysis.
+///
+/// [`macro`]: ../macro.sawtooth_bl.html
+pub fn sawtooth_bl(
+    frequency: f64,
+    amplitude: f64,
+    phase: f64,
+    sample_rate: f64,
+) -> PeriodicFunction {
+    // a non-positive frequency makes `sample_rate / (2.0 * frequency)` zero or negative,
+    // which `floor_raw(...) as u64` would otherwise turn into `u64::MAX` via saturation,
+    // turning the harmonic sum below into an effectively unbounded per-sample loop
+    let k_max = if frequency <= 0.0 {
+        0
+    } else {
+        floor_raw(sample_rate / (2.0 * frequency)) as u64
+    };
+
+    Box::new(move |t| {
+        let sum: f64 = (1..=k_max)
+            .map(|k| {
+                let sign = if k % 2 == 1 { 1.0 } else { -1.0 };
+                let kf = k as f64;
+
+                // the shift of -0.5 turns aligns the series (which, unshifted, rises through
+                // zero at the phase origin) with `sawtooth`'s convention of a discontinuity
+                // there instead
+                sign * sin_raw(TAU * kf * (frequency * t + phase - 0.5)) / kf
+            })
+            .sum();
+
+        (2.0 * amplitude / PI) * sum
+    })
+}
+
+/// Builder macro for band-limited Sawtooth [PeriodicFunction].
+///
+/// Takes up to 4 arguments - frequency, sample_rate, {amplitude, {phase}}.
+///
+/// | argument | unit | notes |
+/// | -------- | ---- | ----- |
+/// | frequency | Hz | Frequecy of the periodic function. Also: 1 / period |
+/// | sample_rate | Hz | Sample rate of the [`Waveform`] this will be mixed into; bounds the harmonic count. |
+/// | amplitude | *arbitrary* | The amplitude of the function in 0-peak notation. |
+/// | phase | *periods* | The phase shift of the function. Value of 1 means full shift around.
+///
+/// [`Waveform`]: ../struct.Waveform.html
+#[macro_export]
+macro_rules! sawtooth_bl {
+    ($frequency:expr, $sample_rate:expr) => {
+        sawtooth_bl!($frequency, $sample_rate, 1.0, 0.0)
+    };
+    ($frequency:expr, $sample_rate:expr, $amplitude:expr) => {
+        sawtooth_bl!($frequency, $sample_rate, $amplitude, 0.0)
+    };
+    ($frequency:expr, $sample_rate:expr, $amplitude:expr, $phase:expr) => {
+        $crate::periodic_functions::sawtooth_bl(
+            $frequency as f64,
+            $amplitude as f64,
+            $phase as f64,
+            $sample_rate as f64,
+        )
+    };
+}
+
+/// Band-limited Square function builder. See the [`macro`] for more info.
+///
+/// Like [`sawtooth_bl`], but for the square wave: synthesizes a 50% duty cycle square as a
+/// Fourier series over its odd harmonics, truncated below the Nyquist limit of
+/// `sample_rate`. Prefer this over [`square`] whenever the output feeds into spectral
+/// analysis; unlike `square`, the duty cycle is not adjustable.
+///
+/// [`macro`]: ../macro.square_bl.html
+pub fn square_bl(frequency: f64, amplitude: f64, phase: f64, sample_rate: f64) -> PeriodicFunction {
+    // a non-positive frequency makes `sample_rate / (2.0 * frequency)` zero or negative,
+    // which `floor_raw(...) as u64` would otherwise turn into `u64::MAX` via saturation,
+    // turning the harmonic sum below into an effectively unbounded per-sample loop
+    let k_max = if frequency <= 0.0 {
+        0
+    } else {
+        floor_raw(sample_rate / (2.0 * frequency)) as u64
+    };
+
+    Box::new(move |t| {
+        let sum: f64 = (1..=k_max)
+            .filter(|k| k % 2 == 1)
+            .map(|k| {
+                let kf = k as f64;
+
+                // `square`'s own phase convention shifts `t` before scaling by frequency
+                // (see `_square`), so the harmonic-scaled phase term must mirror that here
+                // rather than sawtooth_bl's `frequency * t + phase` form
+                sin_raw(TAU * kf * frequency * (t - phase)) / kf
+            })
+            .sum();
+
+        (4.0 * amplitude / PI) * sum
+    })
+}
+
+/// Builder macro for band-limited Square [PeriodicFunction].
+///
+/// Takes up to 4 arguments - frequency, sample_rate, {amplitude, {phase}}.
+///
+/// | argument | unit | notes |
+/// | -------- | ---- | ----- |
+/// | frequency | Hz | Frequecy of the periodic function. Also: 1 / period |
+/// | sample_rate | Hz | Sample rate of the [`Waveform`] this will be mixed into; bounds the harmonic count. |
+/// | amplitude | *arbitrary* | The amplitude of the function in 0-peak notation. |
+/// | phase | seconds | The time shift of the function, subtracted from `t` before scaling by frequency (as in [`square`]). |
+///
+/// [`Waveform`]: ../struct.Waveform.html
+#[macro_export]
+macro_rules! square_bl {
+    ($frequency:expr, $sample_rate:expr) => {
+        square_bl!($frequency, $sample_rate, 1.0, 0.0)
+    };
+    ($frequency:expr, $sample_rate:expr, $amplitude:expr) => {
+        square_bl!($frequency, $sample_rate, $amplitude, 0.0)
+    };
+    ($frequency:expr, $sample_rate:expr, $amplitude:expr, $phase:expr) => {
+        $crate::periodic_functions::square_bl(
+            $frequency as f64,
+            $amplitude as f64,
+            $phase as f64,
+            $sample_rate as f64,
+        )
     };
 }
 
@@ -290,6 +918,124 @@ mod tests {
         assert!(approx_eq!(f64, zero, 0.0, epsilon = EPS));
     }
 
+    #[test]
+    fn default_sine_lut_has_amplitude_of_one_and_no_phase_shift() {
+        let sine = sine_lut!(1);
+
+        let max = sine(0.25);
+        let min = sine(0.75);
+        let zero = sine(0.5);
+
+        assert!(approx_eq!(f64, max, 1.0, epsilon = EPS));
+        assert!(approx_eq!(f64, min, -1.0, epsilon = EPS));
+        assert!(approx_eq!(f64, zero, 0.0, epsilon = EPS));
+    }
+
+    #[test]
+    fn sine_lut_tracks_sine_closely() {
+        let sine = sine!(1);
+        let sine_lut = sine_lut!(1);
+
+        for i in 0..1000 {
+            let t = i as f64 / 1000.0;
+            assert!(approx_eq!(f64, sine(t), sine_lut(t), epsilon = 1e-2));
+        }
+    }
+
+    #[test]
+    fn sine_lut_does_not_panic_at_wrap_boundary() {
+        // here `t * frequency + phase - 0.25` is -2^-55, a hair below an integer, and `wrap01`
+        // rounds it up to exactly `1.0` instead of staying under it, which used to index one
+        // past the table
+        let sine_lut = sine_lut!(1, 1.0, 0.25, 8);
+
+        assert!(approx_eq!(f64, sine_lut(-f64::EPSILON / 8.0), 1.0, epsilon = EPS));
+    }
+
+    #[test]
+    fn constant_frequency_chirp_matches_plain_sine() {
+        // with f_start == f_end the sweep rate is zero, so both sweep kinds degenerate to a
+        // plain sine at that fixed frequency
+        let sine = sine!(2);
+        let linear = chirp!(2, 2, 1, SweepKind::Linear);
+        let exponential = chirp!(2, 2, 1, SweepKind::Exponential);
+
+        for i in 0..1000 {
+            let t = i as f64 / 1000.0;
+            assert!(approx_eq!(f64, linear(t), sine(t), epsilon = EPS));
+            assert!(approx_eq!(f64, exponential(t), sine(t), epsilon = EPS));
+        }
+    }
+
+    #[test]
+    fn exponential_chirp_from_zero_does_not_produce_nan() {
+        // f_start == 0.0 makes the closed-form sweep's f_end / f_start undefined; it should
+        // fall back to a constant-frequency sine instead of NaN-ing every sample
+        let sweep = chirp!(0, 10, 1, SweepKind::Exponential);
+
+        for i in 0..1000 {
+            let t = i as f64 / 1000.0;
+            assert!(sweep(t).is_finite());
+        }
+    }
+
+    #[test]
+    fn chirp_has_amplitude_of_one_by_default() {
+        let linear = chirp!(1, 10, 1, SweepKind::Linear);
+        let exponential = chirp!(1, 10, 1, SweepKind::Exponential);
+
+        for f in [linear, exponential] {
+            for i in 0..1000 {
+                let t = i as f64 / 1000.0;
+                assert!(f(t) <= 1.0 + EPS && f(t) >= -1.0 - EPS);
+            }
+        }
+    }
+
+    #[test]
+    fn exponential_chirp_starts_near_f_start_frequency() {
+        let sweep = chirp!(1, 100, 10, SweepKind::Exponential);
+        let sine = sine!(1);
+
+        // early in a long, slow sweep the instantaneous frequency is still close to f_start
+        for i in 0..10 {
+            let t = i as f64 / 1000.0;
+            assert!(approx_eq!(f64, sweep(t), sine(t), epsilon = 1e-2));
+        }
+    }
+
+    #[test]
+    fn white_noise_is_deterministic_and_bounded() {
+        let a = white_noise!(44100, 1337);
+        let b = white_noise!(44100, 1337);
+
+        for i in 0..10000 {
+            let t = i as f64 / 44100.0;
+            assert_eq!(a(t), b(t));
+            assert!(a(t) >= -1.0 && a(t) <= 1.0);
+        }
+    }
+
+    #[test]
+    fn white_noise_different_seeds_diverge() {
+        let a = white_noise!(44100, 1);
+        let b = white_noise!(44100, 2);
+
+        assert!((0..100).map(|i| i as f64 / 44100.0).any(|t| a(t) != b(t)));
+    }
+
+    #[test]
+    fn pink_noise_is_deterministic_and_bounded() {
+        let a = pink_noise!(44100, 1337);
+        let b = pink_noise!(44100, 1337);
+
+        for i in 0..10000 {
+            let t = i as f64 / 44100.0;
+            assert_eq!(a(t), b(t));
+            assert!(a(t) >= -1.0 && a(t) <= 1.0);
+        }
+    }
+
     #[test]
     fn default_square_has_amplitude_of_one() {
         let square = square!(1);
@@ -302,4 +1048,107 @@ mod tests {
             assert!(approx_eq!(f64, square(x), -1.0, epsilon = EPS))
         }
     }
+
+    #[test]
+    fn square_duty_cycle_controls_high_low_time_ratio() {
+        const SAMPLES: usize = 10000;
+
+        for duty in [0.1, 0.25, 0.5, 0.75, 0.9] {
+            let square = square!(1, 1, 0, duty);
+
+            let high = (0..SAMPLES)
+                .filter(|&i| square(i as f64 / SAMPLES as f64) > 0.0)
+                .count();
+
+            assert!(approx_eq!(
+                f64,
+                high as f64 / SAMPLES as f64,
+                duty,
+                epsilon = 1e-2
+            ));
+        }
+    }
+
+    #[cfg(feature = "simd")]
+    #[test]
+    fn simd_frac_into_matches_scalar_fract() {
+        let input: Vec<f64> = (0..1000).map(|i| i as f64 * 0.0137).collect();
+        let mut out = Vec::with_capacity(input.len());
+        out.resize(input.len(), 0.0);
+
+        simd::frac_into(&input, &mut out);
+
+        for (x, y) in input.iter().zip(out.iter()) {
+            assert!(approx_eq!(f64, x.fract(), *y, epsilon = EPS));
+        }
+    }
+
+    #[test]
+    fn band_limited_sawtooth_approximates_naive_sawtooth() {
+        let naive = sawtooth!(2);
+        let bl = sawtooth_bl!(2, 100000);
+
+        for i in 1..1000 {
+            // skip the neighbourhood of the discontinuity, where a truncated Fourier series
+            // overshoots (Gibbs phenomenon) rather than tracking the naive sawtooth
+            if i % 500 < 10 {
+                continue;
+            }
+
+            let t = i as f64 / 1000.0;
+            assert!(approx_eq!(f64, naive(t), bl(t), epsilon = 1e-1));
+        }
+    }
+
+    #[test]
+    fn band_limited_square_approximates_naive_square() {
+        let naive = square!(2);
+        let bl = square_bl!(2, 100000);
+
+        for i in 1..1000 {
+            // a 50% duty square has a discontinuity every half period, not just once per
+            // period like the sawtooth, so skip around both of them
+            if i % 250 < 20 {
+                continue;
+            }
+
+            let t = i as f64 / 1000.0;
+            assert!(approx_eq!(f64, naive(t), bl(t), epsilon = 1e-1));
+        }
+    }
+
+    #[test]
+    fn band_limited_square_with_phase_approximates_naive_square() {
+        // phase 0 alone can't catch a harmonic not being scaled by phase, since an un-scaled
+        // and a properly-scaled phase term agree there; only a nonzero phase tells them apart
+        let frequency = 2.0;
+        let phase = 0.1;
+        let naive = square!(frequency, 1.0, phase);
+        let bl = square_bl!(frequency, 100000, 1.0, phase);
+
+        for i in 1..1000 {
+            let t = i as f64 / 1000.0;
+
+            // a 50% duty square has a discontinuity every half period, shifted by `phase`;
+            // skip around both of them per period
+            let u = wrap01((t - phase) * frequency);
+            if u < 0.02 || (u - 0.5).abs() < 0.02 {
+                continue;
+            }
+
+            assert!(approx_eq!(f64, naive(t), bl(t), epsilon = 1e-1));
+        }
+    }
+
+    #[test]
+    fn band_limited_sawtooth_and_square_do_not_hang_on_non_positive_frequency() {
+        // a non-positive frequency used to turn `k_max` into `u64::MAX` via saturation,
+        // making the harmonic sum an effectively unbounded loop; they should settle for a
+        // silent (zero) signal instead
+        let sawtooth = sawtooth_bl!(0, 100000);
+        let square = square_bl!(-1, 100000);
+
+        assert_eq!(sawtooth(0.1), 0.0);
+        assert_eq!(square(0.1), 0.0);
+    }
 }
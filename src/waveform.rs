@@ -0,0 +1,285 @@
+//! A waveform built up from summed periodic function components.
+
+use crate::periodic_functions::{bulk_frac, square_sample, sawtooth_sample};
+use crate::PeriodicFunction;
+use alloc::vec::Vec;
+use core::marker::PhantomData;
+
+/// A single component of a [`Waveform`].
+///
+/// [`Sawtooth`] and [`Square`] are kept as their own variants (rather than going through
+/// [`PeriodicFunction`] like everything else) so that [`Waveform::sample_into`] can evaluate
+/// their phase-wrapping arithmetic in bulk via [`bulk_frac`] instead of one sample at a time.
+///
+/// [`Sawtooth`]: Component::Sawtooth
+/// [`Square`]: Component::Square
+enum Component {
+    Function(PeriodicFunction),
+    Sawtooth {
+        frequency: f64,
+        amplitude: f64,
+        phase: f64,
+    },
+    Square {
+        frequency: f64,
+        amplitude: f64,
+        phase: f64,
+        duty: f64,
+    },
+}
+
+impl Component {
+    fn eval(&self, t: f64) -> f64 {
+        match self {
+            Component::Function(f) => f(t),
+            Component::Sawtooth {
+                frequency,
+                amplitude,
+                phase,
+            } => sawtooth_sample(t, *frequency, *amplitude, *phase),
+            Component::Square {
+                frequency,
+                amplitude,
+                phase,
+                duty,
+            } => square_sample(t, *frequency, *amplitude, *phase, *duty),
+        }
+    }
+}
+
+/// A sample type a [`Waveform`] can be generated into.
+///
+/// Implemented for `f32` and `f64`; add more impls here for other output types (e.g. PCM
+/// integer formats) as they're needed.
+pub trait Sample {
+    /// Converts the sum of a sample's components into this sample type.
+    fn from_sum(sum: f64) -> Self;
+}
+
+impl Sample for f64 {
+    fn from_sum(sum: f64) -> Self {
+        sum
+    }
+}
+
+impl Sample for f32 {
+    fn from_sum(sum: f64) -> Self {
+        sum as f32
+    }
+}
+
+/// A waveform: a fixed sample rate plus a collection of [`PeriodicFunction`] components,
+/// summed together and evaluated either one sample at a time (via [`Iterator`]) or in bulk
+/// (via [`Waveform::sample_into`]).
+///
+/// # Examples
+///
+/// ```
+/// use wavegen::{sine, Waveform};
+///
+/// let mut waveform: Waveform<f64> = Waveform::new(44100.0);
+/// waveform.add_component(sine!(50));
+///
+/// let first_ten: Vec<f64> = waveform.take(10).collect();
+/// ```
+pub struct Waveform<T> {
+    sample_rate: f64,
+    components: Vec<Component>,
+    index: u64,
+    _sample: PhantomData<T>,
+}
+
+impl<T> Waveform<T> {
+    /// Creates an empty waveform at the given sample rate (in Hz).
+    pub fn new(sample_rate: f64) -> Self {
+        Self {
+            sample_rate,
+            components: Vec::new(),
+            index: 0,
+            _sample: PhantomData,
+        }
+    }
+
+    /// Adds an arbitrary [`PeriodicFunction`] component, e.g. one built by [`sine!`],
+    /// [`crate::chirp!`] or [`crate::periodic_functions::custom`].
+    pub fn add_component(&mut self, component: PeriodicFunction) -> &mut Self {
+        self.components.push(Component::Function(component));
+        self
+    }
+
+    /// Adds a sawtooth component, evaluated through [`Waveform::sample_into`]'s bulk path
+    /// rather than [`sawtooth!`]'s one-at-a-time [`PeriodicFunction`].
+    pub fn add_sawtooth(&mut self, frequency: f64, amplitude: f64, phase: f64) -> &mut Self {
+        self.components.push(Component::Sawtooth {
+            frequency,
+            amplitude,
+            phase,
+        });
+        self
+    }
+
+    /// Adds a square component, evaluated through [`Waveform::sample_into`]'s bulk path
+    /// rather than [`crate::square!`]'s one-at-a-time [`PeriodicFunction`].
+    pub fn add_square(
+        &mut self,
+        frequency: f64,
+        amplitude: f64,
+        phase: f64,
+        duty: f64,
+    ) -> &mut Self {
+        self.components.push(Component::Square {
+            frequency,
+            amplitude,
+            phase,
+            duty,
+        });
+        self
+    }
+
+    fn time_at(&self, index: u64) -> f64 {
+        index as f64 / self.sample_rate
+    }
+}
+
+impl<T: Sample> Iterator for Waveform<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<T> {
+        let t = self.time_at(self.index);
+        self.index += 1;
+
+        let sum: f64 = self.components.iter().map(|c| c.eval(t)).sum();
+
+        Some(T::from_sum(sum))
+    }
+}
+
+impl<T: Sample> Waveform<T> {
+    /// Fills `out` with `out.len()` consecutive samples starting at `start_index`, without
+    /// touching the waveform's own [`Iterator`] position.
+    ///
+    /// This is an alternative to repeatedly calling [`Iterator::next`]: [`Sawtooth`] and
+    /// [`Square`] components have their phase-wrapping arithmetic evaluated in bulk via
+    /// [`bulk_frac`]. With the `simd` feature enabled that uses a SIMD fast path instead of one
+    /// [`f64::fract`] call per sample; without it, the scalar `frac` is still called per sample.
+    ///
+    /// [`Sawtooth`]: Component::Sawtooth
+    /// [`Square`]: Component::Square
+    pub fn sample_into(&self, start_index: u64, out: &mut [T]) {
+        if out.is_empty() {
+            return;
+        }
+
+        let mut sum = alloc::vec![0.0_f64; out.len()];
+        let mut raw = alloc::vec![0.0_f64; out.len()];
+        let mut wrapped = alloc::vec![0.0_f64; out.len()];
+
+        for component in &self.components {
+            match component {
+                Component::Function(f) => {
+                    for (i, s) in sum.iter_mut().enumerate() {
+                        *s += f(self.time_at(start_index + i as u64));
+                    }
+                }
+                Component::Sawtooth {
+                    frequency,
+                    amplitude,
+                    phase,
+                } => {
+                    for (i, r) in raw.iter_mut().enumerate() {
+                        *r = self.time_at(start_index + i as u64) * frequency + phase;
+                    }
+
+                    bulk_frac(&raw, &mut wrapped);
+
+                    for (s, w) in sum.iter_mut().zip(wrapped.iter()) {
+                        *s += 2.0 * amplitude * w - amplitude;
+                    }
+                }
+                Component::Square {
+                    frequency,
+                    amplitude,
+                    phase,
+                    duty,
+                } => {
+                    for (i, r) in raw.iter_mut().enumerate() {
+                        *r = (self.time_at(start_index + i as u64) - phase) * frequency;
+                    }
+
+                    bulk_frac(&raw, &mut wrapped);
+
+                    for (s, w) in sum.iter_mut().zip(wrapped.iter()) {
+                        // as in `square_sample`, negative fractions move into [0, 1)
+                        let w = if *w < 0.0 { *w + 1.0 } else { *w };
+                        *s += if w < *duty { *amplitude } else { -*amplitude };
+                    }
+                }
+            }
+        }
+
+        for (o, s) in out.iter_mut().zip(sum.iter()) {
+            *o = T::from_sum(*s);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{sawtooth, sine};
+    use float_cmp::approx_eq;
+
+    const EPS: f64 = 1e-9;
+
+    #[test]
+    fn sample_into_matches_iterator_for_generic_component() {
+        let mut by_iter: Waveform<f64> = Waveform::new(1000.0);
+        by_iter.add_component(sine!(10));
+        let expected: Vec<f64> = by_iter.take(100).collect();
+
+        let mut waveform: Waveform<f64> = Waveform::new(1000.0);
+        waveform.add_component(sine!(10));
+        let mut actual = alloc::vec![0.0_f64; 100];
+        waveform.sample_into(0, &mut actual);
+
+        for (e, a) in expected.iter().zip(actual.iter()) {
+            assert!(approx_eq!(f64, *e, *a, epsilon = EPS));
+        }
+    }
+
+    #[test]
+    fn sample_into_matches_iterator_for_sawtooth_and_square() {
+        let mut by_iter: Waveform<f64> = Waveform::new(1000.0);
+        by_iter.add_sawtooth(10.0, 1.0, 0.2);
+        by_iter.add_square(5.0, 0.5, 0.1, 0.3);
+        let expected: Vec<f64> = by_iter.take(200).collect();
+
+        let mut waveform: Waveform<f64> = Waveform::new(1000.0);
+        waveform.add_sawtooth(10.0, 1.0, 0.2);
+        waveform.add_square(5.0, 0.5, 0.1, 0.3);
+        let mut actual = alloc::vec![0.0_f64; 200];
+        waveform.sample_into(0, &mut actual);
+
+        for (e, a) in expected.iter().zip(actual.iter()) {
+            assert!(approx_eq!(f64, *e, *a, epsilon = EPS));
+        }
+    }
+
+    #[test]
+    fn sample_into_honors_start_index() {
+        let mut waveform: Waveform<f64> = Waveform::new(1000.0);
+        waveform.add_sawtooth(10.0, 1.0, 0.0);
+
+        let mut from_iter = alloc::vec![0.0_f64; 50];
+        for (i, s) in from_iter.iter_mut().enumerate() {
+            *s = sawtooth!(10)(((100 + i) as f64) / 1000.0);
+        }
+
+        let mut from_batch = alloc::vec![0.0_f64; 50];
+        waveform.sample_into(100, &mut from_batch);
+
+        for (e, a) in from_iter.iter().zip(from_batch.iter()) {
+            assert!(approx_eq!(f64, *e, *a, epsilon = EPS));
+        }
+    }
+}